@@ -1,5 +1,6 @@
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::rc::Rc;
 
 type NodeName = &'static str;
@@ -204,9 +205,15 @@ where
     let costs: &mut HashMap<NodeName, Weight> = &mut costs.borrow_mut();
     let parents: &mut HashMap<NodeName, Option<Link<T>>> = &mut parents.borrow_mut();
 
-    while let Some(closest_node_name) = find_closest_node::<NodeName>(costs, &processed) {
+    let mut frontier: BinaryHeap<Reverse<(Weight, NodeName)>> = BinaryHeap::new();
+    frontier.push(Reverse((0, root.borrow().name)));
+
+    while let Some(Reverse((cost, closest_node_name))) = frontier.pop() {
+        if processed.contains(closest_node_name) {
+            // Stale entry left behind by a cheaper relaxation; skip it.
+            continue;
+        }
         let closest_node: Link<T> = nodes.get(closest_node_name).unwrap().clone();
-        let cost = *costs.get_mut(closest_node_name).unwrap();
         let edges = closest_node.borrow().edges.clone();
         for edge in edges {
             let name = edge.0.borrow().name;
@@ -215,6 +222,7 @@ where
             if old_cost > new_cost {
                 costs.insert(name, new_cost);
                 parents.insert(name, Some(closest_node.clone()));
+                frontier.push(Reverse((new_cost, name)));
             }
         }
         processed.insert(closest_node.borrow().name);
@@ -223,22 +231,804 @@ where
     costs.clone()
 }
 
-fn find_closest_node<T>(
-    costs: &HashMap<NodeName, Weight>,
-    processed: &HashSet<NodeName>,
-) -> Option<NodeName> {
-    let mut closest_weight: Weight = Weight::MAX;
-    let mut closest_node_name: NodeName = "";
-    for (k, v) in costs.iter() {
-        if *v < closest_weight && !processed.contains(k) {
-            closest_weight = *v;
-            closest_node_name = k;
+// Goal-directed search guided by an admissible heuristic. Returns the path from
+// root to goal and its total weight, or None if goal is unreachable.
+fn a_star_find<T, H>(root: Link<T>, goal: NodeName, heuristic: H) -> Option<(Vec<NodeName>, Weight)>
+where
+    T: Clone + Eq,
+    H: Fn(&Node<T>) -> Weight,
+{
+    let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            nodes.borrow_mut().insert(edge.0.borrow().name, edge.0.clone());
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let nodes = nodes.into_inner();
+
+    let root_name = root.borrow().name;
+    let mut g_score: HashMap<NodeName, Weight> = HashMap::from([(root_name, 0)]);
+    let mut came_from: HashMap<NodeName, NodeName> = HashMap::new();
+    // `open` holds (f, g, name) entries. A node can be pushed more than once
+    // as cheaper paths to it are found, so staleness is checked against
+    // `g_score` rather than a permanent "finalized" set — that lets a node
+    // be reopened whenever a strictly smaller `g` turns up, which is required
+    // for correctness when `heuristic` is admissible but not consistent.
+    let mut open: BinaryHeap<Reverse<(Weight, Weight, NodeName)>> = BinaryHeap::new();
+    open.push(Reverse((heuristic(&root.borrow()), 0, root_name)));
+
+    while let Some(Reverse((_, g, name))) = open.pop() {
+        if g > *g_score.get(name).unwrap() {
+            continue;
+        }
+        if name == goal {
+            break;
+        }
+
+        let node = nodes.get(name).unwrap();
+        for edge in &node.borrow().edges {
+            let neighbor = edge.0.borrow();
+            let new_g = g + edge.1;
+            let is_better = match g_score.get(neighbor.name) {
+                Some(&old_g) => new_g < old_g,
+                None => true,
+            };
+            if is_better {
+                g_score.insert(neighbor.name, new_g);
+                came_from.insert(neighbor.name, name);
+                open.push(Reverse((new_g + heuristic(&neighbor), new_g, neighbor.name)));
+            }
+        }
+    }
+
+    let goal_g = *g_score.get(goal)?;
+    let mut path = vec![goal];
+    let mut current = goal;
+    while let Some(&prev) = came_from.get(current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+    Some((path, goal_g))
+}
+
+// A node whose cost could still be lowered after the V-1 relaxation passes:
+// it lies on a negative cycle or is reachable from one.
+#[derive(Debug, PartialEq, Eq)]
+struct NegativeCycle(NodeName);
+
+// Single-source shortest paths over a caller-supplied signed edge list,
+// detecting negative cycles. The `Rc<RefCell<Node<T>>>` graph can't carry
+// this directly since its `Weight` is unsigned, so `edges` is the source of
+// truth here rather than a traversal of `root`'s outgoing edges.
+fn bellman_ford(root: NodeName, edges: &[(NodeName, NodeName, i64)]) -> Result<HashMap<NodeName, i64>, NegativeCycle> {
+    let mut cost: HashMap<NodeName, i64> = HashMap::from([(root, 0)]);
+    for &(u, v, _) in edges {
+        cost.entry(u).or_insert(i64::MAX);
+        cost.entry(v).or_insert(i64::MAX);
+    }
+
+    for _ in 0..cost.len().saturating_sub(1) {
+        for &(u, v, w) in edges {
+            let cost_u = *cost.get(u).unwrap();
+            if cost_u == i64::MAX {
+                continue;
+            }
+            if cost_u + w < *cost.get(v).unwrap() {
+                cost.insert(v, cost_u + w);
+            }
+        }
+    }
+
+    for &(u, v, w) in edges {
+        let cost_u = *cost.get(u).unwrap();
+        if cost_u != i64::MAX && cost_u + w < *cost.get(v).unwrap() {
+            return Err(NegativeCycle(v));
+        }
+    }
+
+    Ok(cost)
+}
+
+type DistanceMatrix = HashMap<(NodeName, NodeName), Weight>;
+type PredecessorMatrix = HashMap<(NodeName, NodeName), NodeName>;
+
+// All-pairs shortest distances plus a predecessor matrix for reconstructing
+// any u->v path. For u != v, absence of a `dist`/`next` entry for a pair means
+// no path between them is known; `next` holds no (u, u) entries.
+fn floyd_warshall<T>(root: Link<T>) -> (DistanceMatrix, PredecessorMatrix)
+where
+    T: Clone + Eq,
+{
+    let names: RefCell<Vec<NodeName>> = RefCell::new(Vec::new());
+    let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            let node = edge.0.borrow();
+            names.borrow_mut().push(node.name);
+            nodes.borrow_mut().insert(node.name, edge.0.clone());
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let names = names.into_inner();
+    let nodes = nodes.into_inner();
+
+    let mut dist: HashMap<(NodeName, NodeName), Weight> = HashMap::new();
+    let mut next: HashMap<(NodeName, NodeName), NodeName> = HashMap::new();
+
+    for &u in &names {
+        dist.insert((u, u), 0);
+        for edge in &nodes.get(u).unwrap().borrow().edges {
+            let v = edge.0.borrow().name;
+            let is_better = match dist.get(&(u, v)) {
+                Some(&old) => edge.1 < old,
+                None => true,
+            };
+            if is_better {
+                dist.insert((u, v), edge.1);
+                next.insert((u, v), v);
+            }
         }
     }
-    if closest_node_name.is_empty() {
-        return None;
+
+    for &k in &names {
+        for &i in &names {
+            let Some(&dist_ik) = dist.get(&(i, k)) else {
+                continue;
+            };
+            for &j in &names {
+                let Some(&dist_kj) = dist.get(&(k, j)) else {
+                    continue;
+                };
+                let through = dist_ik + dist_kj;
+                let is_better = match dist.get(&(i, j)) {
+                    Some(&old) => through < old,
+                    None => true,
+                };
+                if is_better {
+                    dist.insert((i, j), through);
+                    let next_ik = *next.get(&(i, k)).unwrap();
+                    next.insert((i, j), next_ik);
+                }
+            }
+        }
+    }
+
+    (dist, next)
+}
+
+// Follows `next` (as produced by `floyd_warshall`) from u to v. Returns an
+// empty path if no path is known.
+fn reconstruct_path(next: &PredecessorMatrix, u: NodeName, v: NodeName) -> Vec<NodeName> {
+    if u != v && !next.contains_key(&(u, v)) {
+        return Vec::new();
+    }
+    let mut path = vec![u];
+    let mut current = u;
+    while current != v {
+        current = *next.get(&(current, v)).unwrap();
+        path.push(current);
+    }
+    path
+}
+
+// Strongly connected components in reverse topological order, found with a
+// single DFS (Tarjan's algorithm).
+fn tarjan_scc<T>(root: Link<T>) -> Vec<Vec<NodeName>>
+where
+    T: Clone + Eq,
+{
+    let names: RefCell<Vec<NodeName>> = RefCell::new(Vec::new());
+    let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            let node = edge.0.borrow();
+            names.borrow_mut().push(node.name);
+            nodes.borrow_mut().insert(node.name, edge.0.clone());
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let names = names.into_inner();
+    let nodes = nodes.into_inner();
+
+    let mut counter: usize = 0;
+    let mut index: HashMap<NodeName, usize> = HashMap::new();
+    let mut lowlink: HashMap<NodeName, usize> = HashMap::new();
+    let mut on_stack: HashSet<NodeName> = HashSet::new();
+    let mut stack: Vec<NodeName> = Vec::new();
+    let mut components: Vec<Vec<NodeName>> = Vec::new();
+
+    for &name in &names {
+        if !index.contains_key(name) {
+            tarjan_connect(
+                name,
+                &nodes,
+                &mut counter,
+                &mut index,
+                &mut lowlink,
+                &mut on_stack,
+                &mut stack,
+                &mut components,
+            );
+        }
     }
-    Some(closest_node_name)
+
+    components
+}
+
+#[allow(clippy::too_many_arguments)]
+fn tarjan_connect<T>(
+    v: NodeName,
+    nodes: &HashMap<NodeName, Link<T>>,
+    counter: &mut usize,
+    index: &mut HashMap<NodeName, usize>,
+    lowlink: &mut HashMap<NodeName, usize>,
+    on_stack: &mut HashSet<NodeName>,
+    stack: &mut Vec<NodeName>,
+    components: &mut Vec<Vec<NodeName>>,
+) where
+    T: Clone + Eq,
+{
+    index.insert(v, *counter);
+    lowlink.insert(v, *counter);
+    *counter += 1;
+    stack.push(v);
+    on_stack.insert(v);
+
+    let edges = nodes.get(v).unwrap().borrow().edges.clone();
+    for edge in edges {
+        let w = edge.0.borrow().name;
+        if !index.contains_key(w) {
+            tarjan_connect(w, nodes, counter, index, lowlink, on_stack, stack, components);
+            let new_low = (*lowlink.get(v).unwrap()).min(*lowlink.get(w).unwrap());
+            lowlink.insert(v, new_low);
+        } else if on_stack.contains(w) {
+            let new_low = (*lowlink.get(v).unwrap()).min(*index.get(w).unwrap());
+            lowlink.insert(v, new_low);
+        }
+    }
+
+    if lowlink.get(v) == index.get(v) {
+        let mut component = Vec::new();
+        loop {
+            let w = stack.pop().unwrap();
+            on_stack.remove(w);
+            component.push(w);
+            if w == v {
+                break;
+            }
+        }
+        components.push(component);
+    }
+}
+
+thread_local! {
+    // `NodeName` is pinned to `&'static str`, so condensed components need a
+    // leaked "C{id}" string to name themselves. Caching those here bounds the
+    // leak to one string per distinct id instead of one per `condensation` call.
+    static COMPONENT_NAMES: RefCell<Vec<NodeName>> = const { RefCell::new(Vec::new()) };
+}
+
+fn component_name(id: usize) -> NodeName {
+    COMPONENT_NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+        while names.len() <= id {
+            let leaked: NodeName = Box::leak(format!("C{}", names.len()).into_boxed_str());
+            names.push(leaked);
+        }
+        names[id]
+    })
+}
+
+// Collapses each strongly connected component into a single node (its data
+// is the component id), yielding a DAG that `depth_first_topological_sort`
+// can process without panicking.
+fn condensation<T>(root: Link<T>) -> Link<usize>
+where
+    T: Clone + Eq,
+{
+    let components = tarjan_scc(root.clone());
+    let mut component_of: HashMap<NodeName, usize> = HashMap::new();
+    for (id, component) in components.iter().enumerate() {
+        for &name in component {
+            component_of.insert(name, id);
+        }
+    }
+
+    let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            nodes.borrow_mut().insert(edge.0.borrow().name, edge.0.clone());
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let nodes = nodes.into_inner();
+
+    let condensed: Vec<Link<usize>> = (0..components.len())
+        .map(|id| Node::new(component_name(id), id))
+        .collect();
+
+    let mut linked: HashSet<(usize, usize)> = HashSet::new();
+    for (name, node) in &nodes {
+        let from_id = *component_of.get(name).unwrap();
+        for edge in &node.borrow().edges {
+            let to_id = *component_of.get(edge.0.borrow().name).unwrap();
+            if to_id != from_id && linked.insert((from_id, to_id)) {
+                condensed[from_id].borrow_mut().add_edge(condensed[to_id].clone(), edge.1);
+            }
+        }
+    }
+
+    let root_id = *component_of.get(root.borrow().name).unwrap();
+    condensed[root_id].clone()
+}
+
+// Disjoint-set over `NodeName`s with path compression and union by rank.
+struct UnionFind {
+    parent: HashMap<NodeName, NodeName>,
+    rank: HashMap<NodeName, usize>,
+}
+
+impl UnionFind {
+    fn new(names: impl IntoIterator<Item = NodeName>) -> Self {
+        let mut parent = HashMap::new();
+        let mut rank = HashMap::new();
+        for name in names {
+            parent.insert(name, name);
+            rank.insert(name, 0);
+        }
+        Self { parent, rank }
+    }
+
+    fn find(&mut self, name: NodeName) -> NodeName {
+        let parent = *self.parent.get(name).unwrap();
+        if parent == name {
+            return name;
+        }
+        let root = self.find(parent);
+        self.parent.insert(name, root);
+        root
+    }
+
+    // Unions the sets containing `a` and `b`, returning false if they were
+    // already in the same set.
+    fn union(&mut self, a: NodeName, b: NodeName) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+        let rank_a = *self.rank.get(root_a).unwrap();
+        let rank_b = *self.rank.get(root_b).unwrap();
+        if rank_a < rank_b {
+            self.parent.insert(root_a, root_b);
+        } else if rank_a > rank_b {
+            self.parent.insert(root_b, root_a);
+        } else {
+            self.parent.insert(root_b, root_a);
+            self.rank.insert(root_a, rank_a + 1);
+        }
+        true
+    }
+}
+
+// Kruskal's algorithm over the undirected view of the graph's edges.
+fn minimum_spanning_tree<T>(root: Link<T>) -> Vec<(NodeName, NodeName, Weight)>
+where
+    T: Clone + Eq,
+{
+    let names: RefCell<Vec<NodeName>> = RefCell::new(Vec::new());
+    let edges: RefCell<Vec<(NodeName, NodeName, Weight)>> = RefCell::new(Vec::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            let node = edge.0.borrow();
+            names.borrow_mut().push(node.name);
+            for out_edge in &node.edges {
+                edges.borrow_mut().push((node.name, out_edge.0.borrow().name, out_edge.1));
+            }
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let names = names.into_inner();
+    let mut edges = edges.into_inner();
+    edges.sort_by_key(|&(_, _, weight)| weight);
+
+    let mut forest = UnionFind::new(names.iter().copied());
+    let mut mst = Vec::new();
+    for (u, v, weight) in edges {
+        if forest.union(u, v) {
+            mst.push((u, v, weight));
+            if mst.len() == names.len() - 1 {
+                break;
+            }
+        }
+    }
+
+    mst
+}
+
+// A monoid-parameterized segment tree: `identity` and `combine` define the
+// monoid over `T` that path queries are aggregated with.
+struct SegmentTree<T, F> {
+    size: usize,
+    identity: T,
+    combine: F,
+    tree: Vec<T>,
+}
+
+impl<T, F> SegmentTree<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    fn new(values: Vec<T>, identity: T, combine: F) -> Self {
+        let size = values.len().max(1);
+        let mut tree = vec![identity.clone(); 2 * size];
+        for (i, value) in values.into_iter().enumerate() {
+            tree[size + i] = value;
+        }
+        for i in (1..size).rev() {
+            tree[i] = combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        Self { size, identity, combine, tree }
+    }
+
+    fn update(&mut self, index: usize, value: T) {
+        let mut i = self.size + index;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = (self.combine)(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    // Combines the half-open range [lo, hi).
+    fn query(&self, lo: usize, hi: usize) -> T {
+        let (mut lo, mut hi) = (lo + self.size, hi + self.size);
+        let mut left_acc = self.identity.clone();
+        let mut right_acc = self.identity.clone();
+        while lo < hi {
+            if lo % 2 == 1 {
+                left_acc = (self.combine)(&left_acc, &self.tree[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                right_acc = (self.combine)(&self.tree[hi], &right_acc);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+        (self.combine)(&left_acc, &right_acc)
+    }
+}
+
+// Decomposes a tree-shaped graph into chains so that aggregate queries along
+// any u-v path cost O(log^2 n): all chains share one segment tree, each chain
+// occupying a contiguous range of it, and a path crosses O(log n) chains.
+struct HeavyLightDecomposition<T, F> {
+    parent: HashMap<NodeName, Option<NodeName>>,
+    depth: HashMap<NodeName, usize>,
+    chain_head: HashMap<NodeName, NodeName>,
+    position: HashMap<NodeName, usize>,
+    segment_tree: SegmentTree<T, F>,
+}
+
+impl<T, F> HeavyLightDecomposition<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    fn new(root: Link<T>, identity: T, combine: F) -> Self {
+        let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+        root.borrow().traverse_breadth_first(
+            &|edge| -> ActResult {
+                nodes.borrow_mut().insert(edge.0.borrow().name, edge.0.clone());
+                ActResult::Ok
+            },
+            &mut HashSet::new(),
+        );
+        let nodes = nodes.into_inner();
+        let root_name = root.borrow().name;
+
+        let mut parent: HashMap<NodeName, Option<NodeName>> = HashMap::from([(root_name, None)]);
+        let mut depth: HashMap<NodeName, usize> = HashMap::from([(root_name, 0)]);
+        let mut subtree_size: HashMap<NodeName, usize> = HashMap::new();
+        let mut children: HashMap<NodeName, Vec<NodeName>> = HashMap::new();
+        let mut data: HashMap<NodeName, T> = HashMap::new();
+        hld_compute_sizes(root_name, &nodes, &mut parent, &mut depth, &mut subtree_size, &mut children, &mut data);
+
+        let mut chain_head: HashMap<NodeName, NodeName> = HashMap::new();
+        let mut position: HashMap<NodeName, usize> = HashMap::new();
+        let mut order: Vec<NodeName> = Vec::new();
+        hld_decompose(root_name, root_name, &subtree_size, &children, &mut chain_head, &mut position, &mut order);
+
+        let values: Vec<T> = order.iter().map(|name| data.get(name).unwrap().clone()).collect();
+        let segment_tree = SegmentTree::new(values, identity, combine);
+
+        Self { parent, depth, chain_head, position, segment_tree }
+    }
+
+    fn update(&mut self, node: NodeName, value: T) {
+        let position = *self.position.get(node).unwrap();
+        self.segment_tree.update(position, value);
+    }
+
+    fn query_path(&self, mut u: NodeName, mut v: NodeName) -> T {
+        let mut result = self.segment_tree.identity.clone();
+        while self.chain_head.get(u) != self.chain_head.get(v) {
+            let head_u = *self.chain_head.get(u).unwrap();
+            let head_v = *self.chain_head.get(v).unwrap();
+            if self.depth.get(head_u).unwrap() < self.depth.get(head_v).unwrap() {
+                std::mem::swap(&mut u, &mut v);
+                continue;
+            }
+            let segment = self
+                .segment_tree
+                .query(*self.position.get(head_u).unwrap(), *self.position.get(u).unwrap() + 1);
+            result = (self.segment_tree.combine)(&segment, &result);
+            u = self.parent.get(head_u).unwrap().unwrap();
+        }
+
+        let pos_u = *self.position.get(u).unwrap();
+        let pos_v = *self.position.get(v).unwrap();
+        let (lo, hi) = if pos_u <= pos_v { (pos_u, pos_v) } else { (pos_v, pos_u) };
+        let segment = self.segment_tree.query(lo, hi + 1);
+        (self.segment_tree.combine)(&segment, &result)
+    }
+}
+
+// First HLD pass: parent, depth and subtree size of every node reachable
+// from `v`, assuming the graph is a tree (no node is visited twice).
+#[allow(clippy::too_many_arguments)]
+fn hld_compute_sizes<T>(
+    v: NodeName,
+    nodes: &HashMap<NodeName, Link<T>>,
+    parent: &mut HashMap<NodeName, Option<NodeName>>,
+    depth: &mut HashMap<NodeName, usize>,
+    subtree_size: &mut HashMap<NodeName, usize>,
+    children: &mut HashMap<NodeName, Vec<NodeName>>,
+    data: &mut HashMap<NodeName, T>,
+) -> usize
+where
+    T: Clone,
+{
+    let node = nodes.get(v).unwrap().borrow();
+    data.insert(v, node.data.clone());
+
+    let mut size = 1;
+    let mut kids: Vec<NodeName> = Vec::new();
+    let node_depth = *depth.get(v).unwrap();
+    for edge in &node.edges {
+        let w = edge.0.borrow().name;
+        if depth.contains_key(w) {
+            continue;
+        }
+        parent.insert(w, Some(v));
+        depth.insert(w, node_depth + 1);
+        size += hld_compute_sizes(w, nodes, parent, depth, subtree_size, children, data);
+        kids.push(w);
+    }
+
+    children.insert(v, kids);
+    subtree_size.insert(v, size);
+    size
+}
+
+// Second HLD pass: walk the heaviest child first so it continues the
+// current chain, and start a fresh chain (headed by itself) at every other
+// child.
+fn hld_decompose(
+    v: NodeName,
+    head: NodeName,
+    subtree_size: &HashMap<NodeName, usize>,
+    children: &HashMap<NodeName, Vec<NodeName>>,
+    chain_head: &mut HashMap<NodeName, NodeName>,
+    position: &mut HashMap<NodeName, usize>,
+    order: &mut Vec<NodeName>,
+) {
+    chain_head.insert(v, head);
+    position.insert(v, order.len());
+    order.push(v);
+
+    let kids = children.get(v).unwrap();
+    let heavy_child = kids.iter().max_by_key(|&&child| *subtree_size.get(child).unwrap()).copied();
+    let Some(heavy_child) = heavy_child else {
+        return;
+    };
+
+    hld_decompose(heavy_child, head, subtree_size, children, chain_head, position, order);
+    for &child in kids {
+        if child != heavy_child {
+            hld_decompose(child, child, subtree_size, children, chain_head, position, order);
+        }
+    }
+}
+
+// A flattened, read-only snapshot of a reachable graph in Compressed Sparse
+// Row form. `row_offsets` has length V+1; node `u`'s outgoing edges live in
+// `columns[row_offsets[u]..row_offsets[u + 1]]` (and the parallel `weights`
+// slice), so repeated traversals become contiguous-array scans with no
+// `RefCell` borrows.
+struct Csr<T> {
+    row_offsets: Vec<usize>,
+    columns: Vec<usize>,
+    weights: Vec<Weight>,
+    data: Vec<T>,
+    names: Vec<NodeName>,
+    index: HashMap<NodeName, usize>,
+}
+
+impl<T> Csr<T> {
+    fn neighbors(&self, u: usize) -> &[usize] {
+        &self.columns[self.row_offsets[u]..self.row_offsets[u + 1]]
+    }
+
+    fn edges(&self, u: usize) -> &[Weight] {
+        &self.weights[self.row_offsets[u]..self.row_offsets[u + 1]]
+    }
+
+    fn data(&self, u: usize) -> &T {
+        &self.data[u]
+    }
+}
+
+fn to_csr<T>(root: Link<T>) -> Csr<T>
+where
+    T: Clone,
+{
+    let names: RefCell<Vec<NodeName>> = RefCell::new(Vec::new());
+    let nodes: RefCell<HashMap<NodeName, Link<T>>> = RefCell::new(HashMap::new());
+    root.borrow().traverse_breadth_first(
+        &|edge| -> ActResult {
+            let node = edge.0.borrow();
+            names.borrow_mut().push(node.name);
+            nodes.borrow_mut().insert(node.name, edge.0.clone());
+            ActResult::Ok
+        },
+        &mut HashSet::new(),
+    );
+    let names = names.into_inner();
+    let nodes = nodes.into_inner();
+
+    let index: HashMap<NodeName, usize> = names.iter().enumerate().map(|(i, &name)| (name, i)).collect();
+    let data: Vec<T> = names.iter().map(|name| nodes.get(name).unwrap().borrow().data.clone()).collect();
+
+    let mut row_offsets = Vec::with_capacity(names.len() + 1);
+    let mut columns = Vec::new();
+    let mut weights = Vec::new();
+    row_offsets.push(0);
+    for &name in &names {
+        for edge in &nodes.get(name).unwrap().borrow().edges {
+            columns.push(*index.get(edge.0.borrow().name).unwrap());
+            weights.push(edge.1);
+        }
+        row_offsets.push(columns.len());
+    }
+
+    Csr { row_offsets, columns, weights, data, names, index }
+}
+
+// Same relaxation loop as `dijkstra_find`, scanning `Csr` rows instead of
+// chasing `Rc<RefCell<_>>` edges.
+fn dijkstra_find_csr<T>(csr: &Csr<T>, root: NodeName) -> HashMap<NodeName, Weight> {
+    let node_count = csr.names.len();
+    let mut cost = vec![Weight::MAX; node_count];
+    let mut processed = vec![false; node_count];
+    cost[*csr.index.get(root).unwrap()] = 0;
+
+    let mut frontier: BinaryHeap<Reverse<(Weight, usize)>> = BinaryHeap::new();
+    frontier.push(Reverse((0, *csr.index.get(root).unwrap())));
+
+    while let Some(Reverse((distance, u))) = frontier.pop() {
+        if processed[u] {
+            continue;
+        }
+        processed[u] = true;
+        for (i, &v) in csr.neighbors(u).iter().enumerate() {
+            let new_cost = distance + csr.edges(u)[i];
+            if new_cost < cost[v] {
+                cost[v] = new_cost;
+                frontier.push(Reverse((new_cost, v)));
+            }
+        }
+    }
+
+    csr.names.iter().enumerate().map(|(i, &name)| (name, cost[i])).collect()
+}
+
+// A CSR snapshot carrying signed weights, built straight from a caller-supplied
+// edge list. `Csr<T>` can't be reused here since its `weights: Vec<Weight>` is
+// pinned to the unsigned `Weight = u8` the rest of the graph is stored in.
+struct SignedCsr {
+    row_offsets: Vec<usize>,
+    columns: Vec<usize>,
+    weights: Vec<i64>,
+    names: Vec<NodeName>,
+    index: HashMap<NodeName, usize>,
+}
+
+impl SignedCsr {
+    fn neighbors(&self, u: usize) -> &[usize] {
+        &self.columns[self.row_offsets[u]..self.row_offsets[u + 1]]
+    }
+
+    fn edges(&self, u: usize) -> &[i64] {
+        &self.weights[self.row_offsets[u]..self.row_offsets[u + 1]]
+    }
+
+    fn from_edges(root: NodeName, edges: &[(NodeName, NodeName, i64)]) -> Self {
+        let mut names: Vec<NodeName> = vec![root];
+        let mut index: HashMap<NodeName, usize> = HashMap::from([(root, 0)]);
+        for &(u, v, _) in edges {
+            for name in [u, v] {
+                index.entry(name).or_insert_with(|| {
+                    names.push(name);
+                    names.len() - 1
+                });
+            }
+        }
+
+        let mut rows: Vec<Vec<(usize, i64)>> = vec![Vec::new(); names.len()];
+        for &(u, v, w) in edges {
+            rows[index[u]].push((index[v], w));
+        }
+
+        let mut row_offsets = Vec::with_capacity(names.len() + 1);
+        let mut columns = Vec::new();
+        let mut weights = Vec::new();
+        row_offsets.push(0);
+        for row in rows {
+            for (v, w) in row {
+                columns.push(v);
+                weights.push(w);
+            }
+            row_offsets.push(columns.len());
+        }
+
+        SignedCsr { row_offsets, columns, weights, names, index }
+    }
+}
+
+// Same relaxation loop as `bellman_ford`, scanning `SignedCsr` rows instead of
+// a signed edge slice directly.
+fn bellman_ford_csr(csr: &SignedCsr, root: NodeName) -> Result<HashMap<NodeName, i64>, NegativeCycle> {
+    let node_count = csr.names.len();
+    let mut cost = vec![i64::MAX; node_count];
+    cost[*csr.index.get(root).unwrap()] = 0;
+
+    for _ in 0..node_count.saturating_sub(1) {
+        for u in 0..node_count {
+            if cost[u] == i64::MAX {
+                continue;
+            }
+            for (i, &v) in csr.neighbors(u).iter().enumerate() {
+                let new_cost = cost[u] + csr.edges(u)[i];
+                if new_cost < cost[v] {
+                    cost[v] = new_cost;
+                }
+            }
+        }
+    }
+
+    for u in 0..node_count {
+        if cost[u] == i64::MAX {
+            continue;
+        }
+        for (i, &v) in csr.neighbors(u).iter().enumerate() {
+            if cost[u] + csr.edges(u)[i] < cost[v] {
+                return Err(NegativeCycle(csr.names[v]));
+            }
+        }
+    }
+
+    Ok(csr.names.iter().enumerate().map(|(i, &name)| (name, cost[i])).collect())
 }
 
 #[cfg(test)]
@@ -307,6 +1097,145 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_bellman_ford() {
+        let edges = vec![("R", "A", 2i64), ("R", "B", 5), ("A", "B", -4), ("B", "C", 1)];
+        let cost = bellman_ford("R", &edges).unwrap();
+        assert_eq!(cost, HashMap::from([("R", 0), ("A", 2), ("B", -2), ("C", -1)]));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        let edges = vec![("A", "B", 1i64), ("B", "C", -3), ("C", "A", 1)];
+        assert!(bellman_ford("A", &edges).is_err());
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        let root = gen_graph();
+        let (dist, next) = floyd_warshall(root);
+        assert_eq!(dist.get(&("R", "F")), Some(&22));
+        assert_eq!(reconstruct_path(&next, "R", "F"), vec!["R", "B", "E", "F"]);
+    }
+
+    #[test]
+    fn test_to_csr_dijkstra_find() {
+        let root = gen_graph();
+        let csr = to_csr(root);
+        assert_eq!(*csr.data(0), 0);
+        let costs = dijkstra_find_csr(&csr, "R");
+        assert_eq!(
+            costs,
+            HashMap::from([
+                ("B", 9),
+                ("E", 14),
+                ("R", 0),
+                ("G", 4),
+                ("D", 11),
+                ("A", 1),
+                ("F", 22),
+                ("C", 7)
+            ])
+        )
+    }
+
+    #[test]
+    fn test_to_csr_bellman_ford() {
+        let edges = vec![("R", "A", 2i64), ("R", "B", 5), ("A", "B", -4), ("B", "C", 1)];
+        let csr = SignedCsr::from_edges("R", &edges);
+        let costs = bellman_ford_csr(&csr, "R").unwrap();
+        assert_eq!(costs, HashMap::from([("R", 0), ("A", 2), ("B", -2), ("C", -1)]));
+    }
+
+    #[test]
+    fn test_to_csr_bellman_ford_negative_cycle() {
+        let edges = vec![("A", "B", 1i64), ("B", "C", -3), ("C", "A", 1)];
+        let csr = SignedCsr::from_edges("A", &edges);
+        assert!(bellman_ford_csr(&csr, "A").is_err());
+    }
+
+    #[test]
+    fn test_heavy_light_decomposition() {
+        let root = hld_tree();
+        let mut hld = HeavyLightDecomposition::new(root, 0u64, |a, b| a + b);
+        assert_eq!(hld.query_path("C", "E"), 16);
+        hld.update("C", 10);
+        assert_eq!(hld.query_path("C", "E"), 22);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let root = gen_graph();
+        let mst = minimum_spanning_tree(root);
+        assert_eq!(mst.len(), 7);
+        let total_weight: u32 = mst.iter().map(|&(_, _, weight)| weight as u32).sum();
+        assert_eq!(total_weight, 24);
+    }
+
+    #[test]
+    fn test_tarjan_scc() {
+        let root = scc_graph();
+        let mut sccs = tarjan_scc(root);
+        for component in sccs.iter_mut() {
+            component.sort();
+        }
+        assert_eq!(sccs, vec![vec!["W"], vec!["X", "Y", "Z"]]);
+    }
+
+    #[test]
+    fn test_condensation() {
+        let root = scc_graph();
+        let condensed = condensation(root);
+        let sorted = depth_first_topological_sort(Rc::clone(&condensed));
+        assert_eq!(sorted.len(), 2);
+    }
+
+    #[test]
+    fn test_a_star_find() {
+        let root = gen_graph();
+        let path = a_star_find(root, "F", |_| 0);
+        assert_eq!(path, Some((vec!["R", "B", "E", "F"], 22)));
+    }
+
+    #[test]
+    fn test_a_star_find_with_heuristic() {
+        // Exact remaining distance to "F" along `gen_graph`'s edges, so the
+        // heuristic is non-zero and actually guides the search (unlike the
+        // `|_| 0` case above, which degenerates into plain Dijkstra).
+        let root = gen_graph();
+        let path = a_star_find(root, "F", |node| match node.name {
+            "F" => 0,
+            "E" => 8,
+            "B" => 13,
+            "R" => 22,
+            _ => 0,
+        });
+        assert_eq!(path, Some((vec!["R", "B", "E", "F"], 22)));
+    }
+
+    #[test]
+    fn test_a_star_find_reopens_with_inconsistent_heuristic() {
+        // `h` is admissible (h(n) <= true remaining cost to "G" for every n)
+        // but not consistent: h(A) = 6 overstates the edge A->B(1) relative to
+        // h(B) = 0. That makes a naive "never reopen a finalized node" A*
+        // finalize "B" via the direct S->B(3) edge before the cheaper
+        // S->A->B(2) relaxation runs, returning the wrong path/cost. The true
+        // optimum is S->A->B->G at cost 7, not S->B->G at cost 8.
+        let s = Node::new("S", 0);
+        let a = Node::new("A", 1);
+        let b = Node::new("B", 2);
+        let g = Node::new("G", 3);
+        s.borrow_mut().add_edge(Rc::clone(&a), 1).add_edge(Rc::clone(&b), 3);
+        a.borrow_mut().add_edge(Rc::clone(&b), 1);
+        b.borrow_mut().add_edge(Rc::clone(&g), 5);
+
+        let path = a_star_find(s, "G", |node| match node.name {
+            "A" => 6,
+            _ => 0,
+        });
+        assert_eq!(path, Some((vec!["S", "A", "B", "G"], 7)));
+    }
+
     fn gen_graph() -> Link<u8> {
         let r = Node::new("R", 0);
         let a = Node::new("A", 1);
@@ -326,4 +1255,32 @@ mod tests {
 
         r
     }
+
+    fn hld_tree() -> Link<u64> {
+        let r = Node::new("R", 1);
+        let a = Node::new("A", 2);
+        let b = Node::new("B", 3);
+        let c = Node::new("C", 4);
+        let d = Node::new("D", 5);
+        let e = Node::new("E", 6);
+
+        r.borrow_mut().add_edge(Rc::clone(&a), 1).add_edge(Rc::clone(&b), 1);
+        a.borrow_mut().add_edge(Rc::clone(&c), 1);
+        b.borrow_mut().add_edge(Rc::clone(&d), 1).add_edge(Rc::clone(&e), 1);
+
+        r
+    }
+
+    fn scc_graph() -> Link<u8> {
+        let x = Node::new("X", 0);
+        let y = Node::new("Y", 1);
+        let z = Node::new("Z", 2);
+        let w = Node::new("W", 3);
+
+        x.borrow_mut().add_edge(Rc::clone(&y), 1);
+        y.borrow_mut().add_edge(Rc::clone(&z), 1);
+        z.borrow_mut().add_edge(Rc::clone(&x), 1).add_edge(Rc::clone(&w), 1);
+
+        x
+    }
 }